@@ -269,6 +269,7 @@ impl<'a> ArArchiveBuilder<'a> {
                 uid: 0,
                 gid: 0,
                 perms: 0o644,
+                include_in_symtab: true,
             })
         }
 
@@ -288,6 +289,7 @@ impl<'a> ArArchiveBuilder<'a> {
             archive_kind,
             true,
             false,
+            false,
         )?;
 
         let any_entries = !entries.is_empty();
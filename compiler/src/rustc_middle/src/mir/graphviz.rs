@@ -79,6 +79,7 @@ where
         node_attrs: Some(content_attrs.join(" ")),
         edge_attrs: Some(content_attrs.join(" ")),
         graph_label: Some(label),
+        ..Default::default()
     };
     g.to_dot(w, &settings, subgraph)
 }